@@ -0,0 +1,39 @@
+use num_traits::Float;
+
+use super::Roots;
+
+/// Solves a bi-quadratic equation a4*x^4 + a2*x^2 + a0 = 0.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_biquadratic;
+///
+/// let two_roots = find_roots_biquadratic(1f64, 0f64, -1f64);
+/// // Returns [-1f64, 1f64] as 'x^4 - 1 = 0' has roots -1 and 1
+/// ```
+pub fn find_roots_biquadratic<F: Float>(a4: F, a2: F, a0: F) -> Roots<F> {
+  // Substitute t = x^2, solve the resulting quadratic, then take square
+  // roots of the non-negative solutions for t.
+  let t_roots = super::quadratic::find_roots_quadratic(a4, a2, a0);
+
+  let mut roots = Roots::No([]);
+  for &t in t_roots.as_ref() {
+    if t > F::zero() {
+      let s = t.sqrt();
+      roots = roots.add_new_root(-s).add_new_root(s);
+    } else if t == F::zero() {
+      roots = roots.add_new_root(F::zero());
+    }
+  }
+  roots
+}
+
+#[test]
+fn test_find_roots_biquadratic() {
+  assert_eq!(find_roots_biquadratic(1f64, 0f64, 1f64).as_ref(), []);
+  assert_eq!(find_roots_biquadratic(1f64, 0f64, 0f64).as_ref(), [0f64]);
+  assert_eq!(find_roots_biquadratic(1f32, 0f32, -1f32).as_ref(), [-1f32, 1f32]);
+}
@@ -0,0 +1,69 @@
+use num_traits::Float;
+
+use super::Roots;
+
+/// Solves a cubic equation a3*x^3 + a2*x^2 + a1*x + a0 = 0.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_cubic;
+///
+/// let one_root = find_roots_cubic(1f64, 0f64, 0f64, 0f64);
+/// // Returns [0f64] as 'x^3 = 0' has the single root 0
+/// ```
+pub fn find_roots_cubic<F: Float>(a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+  if a3 == F::zero() {
+    return super::quadratic::find_roots_quadratic(a2, a1, a0);
+  }
+
+  let two = F::one() + F::one();
+  let three = two + F::one();
+  let nine = three * three;
+  let twenty_seven = nine * three;
+  let fifty_four = twenty_seven * two;
+
+  // Normalize to the monic cubic x^3 + a*x^2 + b*x + c = 0.
+  let a = a2 / a3;
+  let b = a1 / a3;
+  let c = a0 / a3;
+  let shift = a / three;
+
+  // Numerical Recipes' trigonometric solution. It keeps the three-real-root
+  // case stable, unlike complex-arithmetic Cardano, which cancels badly near
+  // the discriminant boundary.
+  let q = (a * a - three * b) / nine;
+  let r = (two * a * a * a - nine * a * b + twenty_seven * c) / fifty_four;
+
+  if r * r < q * q * q {
+    // Three distinct real roots.
+    let theta = (r / (q * q * q).sqrt()).acos();
+    let minus_two_sqrt_q = -two * q.sqrt();
+    let pi = (-F::one()).acos();
+    let two_pi = two * pi;
+    Roots::No([])
+      .add_new_root(minus_two_sqrt_q * (theta / three).cos() - shift)
+      .add_new_root(minus_two_sqrt_q * ((theta + two_pi) / three).cos() - shift)
+      .add_new_root(minus_two_sqrt_q * ((theta + two * two_pi) / three).cos() - shift)
+  } else {
+    // A single real root, plus a repeated real root on the boundary r^2 == q^3.
+    let sqrt_term = (r * r - q * q * q).sqrt();
+    let aa = -r.signum() * (r.abs() + sqrt_term).cbrt();
+    let bb = if aa == F::zero() { F::zero() } else { q / aa };
+    let roots = Roots::One([aa + bb - shift]);
+    if sqrt_term == F::zero() && aa != F::zero() {
+      // Here q == aa^2, so the repeated root is -aa - shift.
+      roots.add_new_root(-aa - shift)
+    } else {
+      roots
+    }
+  }
+}
+
+#[test]
+fn test_find_roots_cubic() {
+  assert_eq!(find_roots_cubic(1f64, 0f64, 0f64, 0f64).as_ref(), [0f64]);
+  assert_eq!(find_roots_cubic(1f64, -6f64, 11f64, -6f64).as_ref(), [1f64, 2f64, 3f64]);
+}
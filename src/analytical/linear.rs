@@ -0,0 +1,31 @@
+use num_traits::Float;
+
+use super::Roots;
+
+/// Solves a linear equation a1*x + a0 = 0.
+///
+/// Returns the root, or `Roots::No` if a1 == 0 (no finite root, or every x
+/// is a root when a0 == 0 too).
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_linear;
+///
+/// let root = find_roots_linear(2f64, -4f64);
+/// // Returns [2f64] as '2*x - 4 = 0' has the root 2
+/// ```
+pub fn find_roots_linear<F: Float>(a1: F, a0: F) -> Roots<F> {
+  if a1 == F::zero() {
+    Roots::No([])
+  } else {
+    Roots::One([-a0 / a1])
+  }
+}
+
+#[test]
+fn test_find_roots_linear() {
+  assert_eq!(find_roots_linear(0f32, 0f32).as_ref(), []);
+  assert_eq!(find_roots_linear(0f64, 1f64).as_ref(), []);
+  assert_eq!(find_roots_linear(2f64, -4f64).as_ref(), [2f64]);
+}
@@ -0,0 +1,79 @@
+//! Closed-form solvers for polynomials of degree one through four.
+
+pub mod biquadratic;
+pub mod cubic;
+pub mod linear;
+pub mod quadratic;
+pub mod quartic;
+pub mod quartic_complex;
+pub mod quartic_depressed;
+
+/// The real roots of a polynomial of degree at most four, held inline
+/// without heap allocation.
+///
+/// Roots are always kept sorted in ascending order and free of duplicates;
+/// build one up with repeated calls to [`Roots::add_new_root`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Roots<F> {
+  No([F; 0]),
+  One([F; 1]),
+  Two([F; 2]),
+  Three([F; 3]),
+  Four([F; 4]),
+}
+
+impl<F: Copy> AsRef<[F]> for Roots<F> {
+  /// Borrows the roots as an ordinary slice.
+  fn as_ref(&self) -> &[F] {
+    match self {
+      Roots::No(x) => x,
+      Roots::One(x) => x,
+      Roots::Two(x) => x,
+      Roots::Three(x) => x,
+      Roots::Four(x) => x,
+    }
+  }
+}
+
+impl<F: Copy + PartialOrd> Roots<F> {
+  fn insertion_point(&self, new_root: F) -> Option<usize> {
+    let mut pos = 0;
+    for &x in self.as_ref() {
+      if x == new_root {
+        return None;
+      }
+      if x > new_root {
+        break;
+      }
+      pos += 1;
+    }
+    Some(pos)
+  }
+
+  /// Inserts `new_root` keeping the roots sorted, silently dropping it if
+  /// it already equals an existing root.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the container already holds four roots.
+  pub fn add_new_root(self, new_root: F) -> Self {
+    let pos = match self.insertion_point(new_root) {
+      Some(pos) => pos,
+      None => return self,
+    };
+
+    match (self, pos) {
+      (Roots::No(_), 0) => Roots::One([new_root]),
+      (Roots::One(x), 0) => Roots::Two([new_root, x[0]]),
+      (Roots::One(x), 1) => Roots::Two([x[0], new_root]),
+      (Roots::Two(x), 0) => Roots::Three([new_root, x[0], x[1]]),
+      (Roots::Two(x), 1) => Roots::Three([x[0], new_root, x[1]]),
+      (Roots::Two(x), 2) => Roots::Three([x[0], x[1], new_root]),
+      (Roots::Three(x), 0) => Roots::Four([new_root, x[0], x[1], x[2]]),
+      (Roots::Three(x), 1) => Roots::Four([x[0], new_root, x[1], x[2]]),
+      (Roots::Three(x), 2) => Roots::Four([x[0], x[1], new_root, x[2]]),
+      (Roots::Three(x), 3) => Roots::Four([x[0], x[1], x[2], new_root]),
+      _ => panic!("Roots can only hold up to four values"),
+    }
+  }
+}
@@ -0,0 +1,49 @@
+use num_traits::Float;
+
+use super::Roots;
+
+/// Solves a quadratic equation a2*x^2 + a1*x + a0 = 0.
+///
+/// Returned roots are ordered.
+///
+/// # Examples
+///
+/// ```
+/// use roots::find_roots_quadratic;
+///
+/// let no_roots = find_roots_quadratic(1f64, 0f64, 1f64);
+/// // Returns [] as 'x^2 + 1 = 0' has no real roots
+///
+/// let one_root = find_roots_quadratic(1f64, 0f64, 0f64);
+/// // Returns [0f64] as 'x^2 = 0' has the single root 0
+///
+/// let two_roots = find_roots_quadratic(1f32, 0f32, -1f32);
+/// // Returns [-1f32, 1f32] as 'x^2 - 1 = 0' has roots -1 and 1
+/// ```
+pub fn find_roots_quadratic<F: Float>(a2: F, a1: F, a0: F) -> Roots<F> {
+  if a2 == F::zero() {
+    return super::linear::find_roots_linear(a1, a0);
+  }
+
+  let two = F::one() + F::one();
+  let four = two + two;
+
+  let discriminant = a1 * a1 - four * a2 * a0;
+  if discriminant < F::zero() {
+    Roots::No([])
+  } else if discriminant == F::zero() {
+    Roots::One([-a1 / (two * a2)])
+  } else {
+    let sqrt_discriminant = discriminant.sqrt();
+    Roots::No([])
+      .add_new_root((-a1 - sqrt_discriminant) / (two * a2))
+      .add_new_root((-a1 + sqrt_discriminant) / (two * a2))
+  }
+}
+
+#[test]
+fn test_find_roots_quadratic() {
+  assert_eq!(find_roots_quadratic(1f64, 0f64, 1f64).as_ref(), []);
+  assert_eq!(find_roots_quadratic(1f64, 0f64, 0f64).as_ref(), [0f64]);
+  assert_eq!(find_roots_quadratic(1f32, 0f32, -1f32).as_ref(), [-1f32, 1f32]);
+}
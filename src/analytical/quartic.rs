@@ -22,8 +22,9 @@
 // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::num::Float;
-use std::cmp::Ordering;
+use num_traits::Float;
+
+use super::Roots;
 
 /// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0.
 ///
@@ -41,68 +42,127 @@ use std::cmp::Ordering;
 /// let two_roots = find_roots_quartic(1f32, 0f32, 0f32, 0f32, -1f32);
 /// // Returns [-1f32, 1f32] as 'x^4 - 1 = 0' has roots -1 and 1
 /// ```
-pub fn find_roots_quartic<F:Float>(a4:F, a3:F, a2:F, a1:F, a0:F) -> Vec<F> {
+pub fn find_roots_quartic<F: Float>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
   // Handle non-standard cases
-  let mut roots = if a4 == F::zero() {
+  if a4 == F::zero() {
     // a4 = 0; a3*x^3 + a2*x^2 + a1*x + a0 = 0; solve cubic equation
     super::cubic::find_roots_cubic(a3, a2, a1, a0)
   } else if a0 == F::zero() {
     // a0 = 0; x^4 + a2*x^2 + a1*x = 0; reduce to cubic and arrange results
-    let mut tmp = vec![F::zero()];
-    tmp.push_all(super::cubic::find_roots_cubic(a4, a3, a2, a1).as_slice());
-    tmp
+    super::cubic::find_roots_cubic(a4, a3, a2, a1).add_new_root(F::zero())
   } else if a1 == F::zero() && a3 == F::zero() {
     // a1 = 0, a3 =0; a4*x^4 + a2*x^2 + a0 = 0; solve bi-quadratic equation
     super::biquadratic::find_roots_biquadratic(a4, a2, a0)
   } else {
-    let _2 = F::one() + F::one();
-    let _3 = _2 + F::one();
-    let _4 = _2 + _2;
-    let _8 = _4 + _4;
-    let _16 = _4 * _4;
-    let _64 = _8 * _8;
-    let _256 = _8 * _8 * _4;
+    let two = F::one() + F::one();
+    let three = two + F::one();
+    let four = two + two;
+    let eight = four + four;
+    let sixteen = four * four;
+    let sixty_four = eight * eight;
+    let two_fifty_six = eight * eight * four;
 
     // a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 => x^4 + a*x^3 + b*x^2 + c*x + d = 0.
     let (a, b, c, d) = (a3/a4, a2/a4, a1/a4, a0/a4);
     // x^4 + a*x^3 + b*x^2 + c*x + d = 0 => y^4 + p*y^2 + q*y + r.
-    let _a2 = a*a;
-    let subst = -a3/(_4*a4);
-    let (p, q, r) = ( (_8*b - _3*_a2)/_8, (_a2*a - _4*a*b + _8*c)/_8, (_256*d - _3*_a2*_a2 - _64*c*a + _16*_a2*b)/_256);
+    let a_sq = a*a;
+    let subst = -a3/(four*a4);
+    let (p, q, r) = ( (eight*b - three*a_sq)/eight, (a_sq*a - four*a*b + eight*c)/eight, (two_fifty_six*d - three*a_sq*a_sq - sixty_four*c*a + sixteen*a_sq*b)/two_fifty_six);
 
     let y_roots = super::quartic_depressed::find_roots_quartic_depressed(p, q, r);
-    let x_roots = y_roots.map_in_place(|y| y+subst);
+    let mut x_roots = Roots::No([]);
+    for &y in y_roots.as_ref() {
+      x_roots = x_roots.add_new_root(y + subst);
+    }
     x_roots
-  };
+  }
+}
 
-  roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
-  roots.dedup();
-  roots
+/// Number of Newton-Raphson iterations attempted per root in
+/// [`find_roots_quartic_polished`].
+const POLISH_ITERATIONS: usize = 8;
+
+/// Same as [`find_roots_quartic`], but follows each root with a few
+/// Newton-Raphson iterations against the *original* coefficients.
+///
+/// The closed-form reduction loses precision when roots are clustered or the
+/// coefficients span large magnitudes; this trades a handful of extra
+/// polynomial evaluations per root for a tighter result.
+pub fn find_roots_quartic_polished<F: Float>(a4: F, a3: F, a2: F, a1: F, a0: F) -> Roots<F> {
+  let roots = find_roots_quartic(a4, a3, a2, a1, a0);
+  let mut polished = Roots::No([]);
+  for &x0 in roots.as_ref() {
+    polished = polished.add_new_root(polish_quartic_root(a4, a3, a2, a1, a0, x0));
+  }
+  polished
+}
+
+fn eval_quartic<F: Float>(a4: F, a3: F, a2: F, a1: F, a0: F, x: F) -> F {
+  (((a4 * x + a3) * x + a2) * x + a1) * x + a0
+}
+
+fn polish_quartic_root<F: Float>(a4: F, a3: F, a2: F, a1: F, a0: F, x0: F) -> F {
+  let two = F::one() + F::one();
+  let three = two + F::one();
+  let four = two + two;
+
+  let mut x = x0;
+  let mut best = eval_quartic(a4, a3, a2, a1, a0, x).abs();
+  for _ in 0..POLISH_ITERATIONS {
+    let p = eval_quartic(a4, a3, a2, a1, a0, x);
+    let dp = ((four * a4 * x + three * a3) * x + two * a2) * x + a1;
+    if dp == F::zero() {
+      break;
+    }
+    let candidate = x - p / dp;
+    let candidate_abs = eval_quartic(a4, a3, a2, a1, a0, candidate).abs();
+    if candidate_abs >= best {
+      break;
+    }
+    x = candidate;
+    best = candidate_abs;
+  }
+  x
 }
 
 #[test]
 fn test_find_roots_quartic() {
-  assert_eq!(find_roots_quartic(1f32, 0f32, 0f32, 0f32, 0f32), [0f32]);
-  assert_eq!(find_roots_quartic(1f64, 0f64, 0f64, 0f64, -1f64), [-1f64, 1f64]);
-  assert_eq!(find_roots_quartic(1f64, -10f64, 35f64, -50f64, 24f64), [1f64, 2f64, 3f64, 4f64]);
+  assert_eq!(find_roots_quartic(1f32, 0f32, 0f32, 0f32, 0f32).as_ref(), [0f32]);
+  assert_eq!(find_roots_quartic(1f64, 0f64, 0f64, 0f64, -1f64).as_ref(), [-1f64, 1f64]);
+  assert_eq!(find_roots_quartic(1f64, -10f64, 35f64, -50f64, 24f64).as_ref(), [1f64, 2f64, 3f64, 4f64]);
 
-  match find_roots_quartic(3f64, 5f64, -5f64, -5f64, 2f64).as_slice() {
+  match find_roots_quartic(3f64, 5f64, -5f64, -5f64, 2f64).as_ref() {
     [x1, x2, x3, x4] => {
       assert_float_eq!(1e-15, x1, -2f64 );
       assert_float_eq!(1e-15, x2, -1f64 );
-      assert_float_eq!(1e-15, x3, 0.33333333333333333f64 );
+      assert_float_eq!(1e-15, x3, 0.333_333_333_333_333_3f64 );
       assert_float_eq!(2e-15, x4, 1f64 );
     },
-    _ => { assert!(false); }
+    _ => { unreachable!(); }
   }
 
-  match find_roots_quartic(3f32, 5f32, -5f32, -5f32, 2f32).as_slice() {
+  match find_roots_quartic(3f32, 5f32, -5f32, -5f32, 2f32).as_ref() {
     [x1, x2, x3, x4] => {
       assert_float_eq!(5e-7, x1, -2f32 );
       assert_float_eq!(5e-7, x2, -1f32 );
-      assert_float_eq!(5e-7, x3, 0.33333333333333333f32 );
+      assert_float_eq!(5e-7, x3, 0.333_333_34f32 );
       assert_float_eq!(5e-7, x4, 1f32 );
     },
-    _ => { assert!(false); }
+    _ => { unreachable!(); }
+  }
+}
+
+#[test]
+fn test_find_roots_quartic_polished() {
+  assert_eq!(find_roots_quartic_polished(1f64, -10f64, 35f64, -50f64, 24f64).as_ref(), [1f64, 2f64, 3f64, 4f64]);
+
+  match find_roots_quartic_polished(3f64, 5f64, -5f64, -5f64, 2f64).as_ref() {
+    [x1, x2, x3, x4] => {
+      assert_float_eq!(1e-15, x1, -2f64 );
+      assert_float_eq!(1e-15, x2, -1f64 );
+      assert_float_eq!(1e-15, x3, 0.333_333_333_333_333_3f64 );
+      assert_float_eq!(1e-15, x4, 1f64 );
+    },
+    _ => { unreachable!(); }
   }
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! Complex-root-returning counterpart of [`super::quartic`].
+//!
+//! Gated behind the `num-complex` feature so crates that only need real
+//! roots don't pay for the dependency.
+
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "num-complex")]
+use num_traits::Float;
+
+#[cfg(all(feature = "num-complex", feature = "std", test))]
+use std::vec::Vec;
+#[cfg(all(feature = "num-complex", not(feature = "std"), test))]
+use alloc::vec::Vec;
+
+/// Solves a quadratic equation over the complex numbers, always returning
+/// both roots (counted with multiplicity).
+#[cfg(feature = "num-complex")]
+fn find_roots_quadratic_complex<F: Float>(a2: F, a1: F, a0: F) -> [Complex<F>; 2] {
+  let two = F::one() + F::one();
+  let four = two + two;
+  let discriminant = Complex::new(a1 * a1 - four * a2 * a0, F::zero());
+  let sqrt_discriminant = discriminant.sqrt();
+  let denom = Complex::new(two * a2, F::zero());
+  [
+    (Complex::new(-a1, F::zero()) - sqrt_discriminant) / denom,
+    (Complex::new(-a1, F::zero()) + sqrt_discriminant) / denom,
+  ]
+}
+
+/// Solves a cubic equation over the complex numbers, always returning all
+/// three roots (counted with multiplicity).
+///
+/// Deflates the polynomial by one real root, which a real cubic always has,
+/// then solves the remaining quadratic factor over the complex numbers.
+#[cfg(feature = "num-complex")]
+fn find_roots_cubic_complex<F: Float>(a3: F, a2: F, a1: F, a0: F) -> [Complex<F>; 3] {
+  let a = a2 / a3;
+  let b = a1 / a3;
+
+  let real_root = *super::cubic::find_roots_cubic(a3, a2, a1, a0)
+    .as_ref()
+    .first()
+    .expect("a real cubic always has at least one real root");
+
+  // x^3 + a*x^2 + b*x + c = (x - real_root) * (x^2 + b1*x + b0)
+  let b1 = a + real_root;
+  let b0 = b + real_root * b1;
+  let [q1, q2] = find_roots_quadratic_complex(F::one(), b1, b0);
+
+  [Complex::new(real_root, F::zero()), q1, q2]
+}
+
+/// Solves a quartic equation a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 over
+/// the complex numbers, always returning all four roots (counted with
+/// multiplicity) in a deterministic order.
+///
+/// Unlike [`super::quartic::find_roots_quartic`], no root is silently
+/// dropped: a factor such as `x^2 + 1` inside the quartic contributes its
+/// complex conjugate pair instead of vanishing from the result.
+#[cfg(feature = "num-complex")]
+pub fn find_roots_quartic_complex<F: Float>(a4: F, a3: F, a2: F, a1: F, a0: F) -> [Complex<F>; 4] {
+  if a4 == F::zero() {
+    let [x1, x2, x3] = find_roots_cubic_complex(a3, a2, a1, a0);
+    return [Complex::new(F::zero(), F::zero()), x1, x2, x3];
+  }
+  if a0 == F::zero() {
+    let [x1, x2, x3] = find_roots_cubic_complex(a4, a3, a2, a1);
+    return [Complex::new(F::zero(), F::zero()), x1, x2, x3];
+  }
+
+  let two = F::one() + F::one();
+  let three = two + F::one();
+  let four = two + two;
+  let eight = four + four;
+  let sixteen = four * four;
+  let sixty_four = eight * eight;
+  let two_fifty_six = eight * eight * four;
+
+  // a4*x^4 + a3*x^3 + a2*x^2 + a1*x + a0 = 0 => x^4 + a*x^3 + b*x^2 + c*x + d = 0.
+  let (a, b, c, d) = (a3 / a4, a2 / a4, a1 / a4, a0 / a4);
+  // x^4 + a*x^3 + b*x^2 + c*x + d = 0 => y^4 + p*y^2 + q*y + r.
+  let a_sq = a * a;
+  let subst = -a3 / (four * a4);
+  let (p, q, r) = (
+    (eight * b - three * a_sq) / eight,
+    (a_sq * a - four * a * b + eight * c) / eight,
+    (two_fifty_six * d - three * a_sq * a_sq - sixty_four * c * a + sixteen * a_sq * b) / two_fifty_six,
+  );
+
+  let shift = Complex::new(subst, F::zero());
+
+  if q == F::zero() {
+    // Depressed biquadratic y^4 + p*y^2 + r = 0: solve for t = y^2 over the
+    // complex numbers, then take both complex square roots of each t.
+    let [t1, t2] = find_roots_quadratic_complex(F::one(), p, r);
+    let s1 = t1.sqrt();
+    let s2 = t2.sqrt();
+    return [shift - s1, shift + s1, shift - s2, shift + s2];
+  }
+
+  // Ferrari's method: the same resolvent-root factorization as the
+  // real-valued solver, just with each resulting quadratic solved over the
+  // complex numbers instead of the reals.
+  let m = super::quartic_depressed::resolvent_root(p, q, r);
+  let sqrt_m = m.sqrt();
+  let half_sum = (p + m) / two;
+  let correction = q / (two * sqrt_m);
+
+  let [y1, y2] = find_roots_quadratic_complex(F::one(), -sqrt_m, half_sum + correction);
+  let [y3, y4] = find_roots_quadratic_complex(F::one(), sqrt_m, half_sum - correction);
+
+  [shift + y1, shift + y2, shift + y3, shift + y4]
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_find_roots_quartic_complex() {
+  // x^4 - 1 = (x-1)(x+1)(x^2+1): two real roots, one complex conjugate pair.
+  let roots = find_roots_quartic_complex(1f64, 0f64, 0f64, 0f64, -1f64);
+
+  let mut real_roots: Vec<f64> = roots.iter().filter(|z| z.im.abs() < 1e-9).map(|z| z.re).collect();
+  real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+  assert_eq!(real_roots, [-1f64, 1f64]);
+
+  assert_eq!(roots.iter().filter(|z| z.im.abs() >= 1e-9).count(), 2);
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_find_roots_quartic_complex_general() {
+  // 3x^4+5x^3-5x^2-5x+2 has roots -2, -1, 1/3, 1; its depressed-quartic
+  // resolvent has q != 0, exercising the general Ferrari branch rather than
+  // the q == 0 early return.
+  let roots = find_roots_quartic_complex(3f64, 5f64, -5f64, -5f64, 2f64);
+
+  let mut real_roots: Vec<f64> = roots.iter().filter(|z| z.im.abs() < 1e-9).map(|z| z.re).collect();
+  real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+  assert_eq!(real_roots.len(), 4);
+  assert_float_eq!(1e-9, real_roots[0], -2f64);
+  assert_float_eq!(1e-9, real_roots[1], -1f64);
+  assert_float_eq!(1e-9, real_roots[2], 0.3333333333333333f64);
+  assert_float_eq!(1e-9, real_roots[3], 1f64);
+}
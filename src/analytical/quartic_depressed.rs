@@ -0,0 +1,73 @@
+use num_traits::Float;
+
+use super::Roots;
+
+/// Picks a positive root `m` of the resolvent cubic
+/// `m^3 + 2*p*m^2 + (p^2 - 4*r)*m - q^2 = 0` used by Ferrari's method, or
+/// `F::zero()` if none exists.
+///
+/// Shared by the real-valued solver here and by
+/// [`super::quartic_complex::find_roots_quartic_complex`], so a fix to the
+/// factorization only has to happen in one place.
+pub(crate) fn resolvent_root<F: Float>(p: F, q: F, r: F) -> F {
+  let two = F::one() + F::one();
+  let four = two + two;
+
+  super::cubic::find_roots_cubic(F::one(), two * p, p * p - four * r, -q * q)
+    .as_ref()
+    .iter()
+    .cloned()
+    .filter(|&m| m > F::zero())
+    .fold(F::zero(), F::max)
+}
+
+/// Solves a depressed quartic equation y^4 + p*y^2 + q*y + r = 0.
+///
+/// Returned roots are ordered. Used internally by `find_roots_quartic` after
+/// it eliminates the cubic term of a general quartic.
+pub fn find_roots_quartic_depressed<F: Float>(p: F, q: F, r: F) -> Roots<F> {
+  if q == F::zero() {
+    // y^4 + p*y^2 + r = 0
+    return super::biquadratic::find_roots_biquadratic(F::one(), p, r);
+  }
+
+  let m = resolvent_root(p, q, r);
+  if m <= F::zero() {
+    return Roots::No([]);
+  }
+
+  // Ferrari's method: with m a positive resolvent root,
+  // (y^2 + p/2 + m/2)^2 = m*(y - q/(2m))^2, which factors the quartic into
+  // y^2 -/+ sqrt(m)*y + (p+m)/2 +/- q/(2*sqrt(m)).
+  let two = F::one() + F::one();
+  let sqrt_m = m.sqrt();
+  let half_sum = (p + m) / two;
+  let correction = q / (two * sqrt_m);
+
+  let mut roots = Roots::No([]);
+  for &y in super::quadratic::find_roots_quadratic(F::one(), -sqrt_m, half_sum + correction).as_ref() {
+    roots = roots.add_new_root(y);
+  }
+  for &y in super::quadratic::find_roots_quadratic(F::one(), sqrt_m, half_sum - correction).as_ref() {
+    roots = roots.add_new_root(y);
+  }
+  roots
+}
+
+#[test]
+fn test_find_roots_quartic_depressed() {
+  assert_eq!(find_roots_quartic_depressed(0f64, 0f64, 0f64).as_ref(), [0f64]);
+
+  // p, q, r for 3x^4+5x^3-5x^2-5x+2 (roots -2, -1, 1/3, 1) after eliminating
+  // the cubic term; the resolvent cubic here has three real roots, which is
+  // the branch the Ferrari factorization must get right.
+  match find_roots_quartic_depressed(-2.7083333333333335f64, 0.30092592592592626f64, 0.9813368055555556f64).as_ref() {
+    [y1, y2, y3, y4] => {
+      assert_float_eq!(1e-9, y1, -1.5833333333333333f64);
+      assert_float_eq!(1e-9, y2, -0.5833333333333334f64);
+      assert_float_eq!(1e-9, y3, 0.75f64);
+      assert_float_eq!(1e-9, y4, 1.4166666666666667f64);
+    },
+    _ => { unreachable!(); }
+  }
+}
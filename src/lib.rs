@@ -0,0 +1,81 @@
+// Copyright (c) 2015, Mikhail Vorotilov
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions are met:
+//
+// * Redistributions of source code must retain the above copyright notice, this
+//   list of conditions and the following disclaimer.
+//
+// * Redistributions in binary form must reproduce the above copyright notice,
+//   this list of conditions and the following disclaimer in the documentation
+//   and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+// AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+// CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+// OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Find real roots of linear, quadratic, cubic and quartic equations.
+//!
+//! The solvers for degrees one through four use closed-form analytical
+//! solutions, so they are fast and exact up to floating point precision
+//! ([`find_roots_quartic_polished`] adds a few Newton-Raphson steps on top).
+//! Roots are returned in a [`Roots`]
+//! value, which holds up to four roots inline with no heap allocation.
+//!
+//! The crate is `no_std`-friendly: by default it relies on `std` for the
+//! handful of transcendental operations the solvers need (`sqrt`, `cbrt`,
+//! `acos`, `cos`), but building with `default-features = false` and the
+//! `libm` feature routes those through the `libm` crate instead, so `roots`
+//! compiles for bare-metal targets such as `thumbv6m-none-eabi`.
+//!
+//! The real-root functions drop non-real roots (e.g. a quartic's `x^2 + 1`
+//! factor). Enabling the `num-complex` feature additionally exposes
+//! [`find_roots_quartic_complex`], which returns every root, real or not,
+//! and [`numerical::polynomial::find_roots_polynomial`], which extends past
+//! degree four via Durand-Kerner iteration since no closed form exists there.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate num_traits;
+
+#[cfg(all(feature = "num-complex", not(feature = "std")))]
+extern crate alloc;
+
+/// Asserts that `$x` is within `$tol` of `$y`. Used by the `#[test]`s
+/// throughout `analytical`, which compare against known roots up to
+/// floating-point precision rather than exactly.
+#[cfg(test)]
+macro_rules! assert_float_eq {
+  ($tol:expr, $x:expr, $y:expr) => {
+    assert!(
+      ($x - $y).abs() < $tol,
+      "{} is not within {} of {}",
+      $x,
+      $tol,
+      $y
+    );
+  };
+}
+
+pub mod analytical;
+pub mod numerical;
+
+pub use analytical::Roots;
+pub use analytical::biquadratic::find_roots_biquadratic;
+pub use analytical::cubic::find_roots_cubic;
+pub use analytical::linear::find_roots_linear;
+pub use analytical::quadratic::find_roots_quadratic;
+pub use analytical::quartic::{find_roots_quartic, find_roots_quartic_polished};
+#[cfg(feature = "num-complex")]
+pub use analytical::quartic_complex::find_roots_quartic_complex;
+pub use analytical::quartic_depressed::find_roots_quartic_depressed;
+#[cfg(feature = "num-complex")]
+pub use numerical::polynomial::{find_roots_polynomial, find_roots_polynomial_real};
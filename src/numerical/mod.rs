@@ -0,0 +1,8 @@
+//! Iterative solvers for problems that have no closed-form solution, such as
+//! polynomials of degree five and above.
+//!
+//! Everything here is gated behind the `num-complex` Cargo feature, declared
+//! in the crate manifest alongside `num-traits`; build with
+//! `--features num-complex` (and `std` or `libm`) to use it.
+
+pub mod polynomial;
@@ -0,0 +1,118 @@
+//! General degree-N polynomial roots via Durand-Kerner iteration.
+//!
+//! Gated behind the `num-complex` feature, same as
+//! [`crate::analytical::quartic_complex`]. The closed-form `analytical`
+//! module handles degrees one through four exactly; this module fills in
+//! everything past that, at the cost of an iterative numerical solve.
+
+#[cfg(feature = "num-complex")]
+use core::cmp::Ordering;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "num-complex")]
+use num_traits::Float;
+
+#[cfg(all(feature = "num-complex", feature = "std"))]
+use std::vec::Vec;
+#[cfg(all(feature = "num-complex", not(feature = "std")))]
+use alloc::vec::Vec;
+
+/// Maximum number of simultaneous-iteration sweeps before giving up on
+/// further convergence.
+#[cfg(feature = "num-complex")]
+const MAX_ITERATIONS: usize = 200;
+
+/// Finds all complex roots of a degree-N polynomial via Durand-Kerner
+/// (Weierstrass) simultaneous iteration.
+///
+/// `coeffs` lists coefficients from the highest-degree term down to the
+/// constant term, e.g. `&[1.0, 0.0, -1.0]` for `x^2 - 1`. Returns one root
+/// per degree, counted with multiplicity; an empty `Vec` if `coeffs` is
+/// empty or identically zero.
+///
+/// Degrees one through four are served exactly (and faster) by
+/// [`crate::find_roots_linear`] through [`crate::find_roots_quartic_complex`];
+/// reach for this only once a closed form stops being available.
+#[cfg(feature = "num-complex")]
+pub fn find_roots_polynomial<F: Float>(coeffs: &[F]) -> Vec<Complex<F>> {
+  // Drop leading zero coefficients so the degree matches the data.
+  let coeffs = match coeffs.iter().position(|&c| c != F::zero()) {
+    Some(i) => &coeffs[i..],
+    None => return Vec::new(),
+  };
+
+  let degree = coeffs.len() - 1;
+  if degree == 0 {
+    return Vec::new();
+  }
+
+  // Normalize to a monic polynomial.
+  let leading = coeffs[0];
+  let monic: Vec<Complex<F>> = coeffs.iter().map(|&c| Complex::new(c / leading, F::zero())).collect();
+
+  // Seed the n root estimates at distinct points z_k = seed^k; |seed| is just
+  // under one, so they lie on a slowly shrinking spiral around the origin.
+  let seed = Complex::new(F::from(0.4).unwrap(), F::from(0.9).unwrap());
+  let mut roots = Vec::with_capacity(degree);
+  let mut z = Complex::new(F::one(), F::zero());
+  for _ in 0..degree {
+    roots.push(z);
+    z = z * seed;
+  }
+
+  let tolerance = F::from(1e-12).unwrap_or_else(F::epsilon);
+
+  for _ in 0..MAX_ITERATIONS {
+    let mut max_update = F::zero();
+    for i in 0..degree {
+      let p = eval_horner(&monic, roots[i]);
+      let mut denom = Complex::new(F::one(), F::zero());
+      for j in 0..degree {
+        if j != i {
+          denom = denom * (roots[i] - roots[j]);
+        }
+      }
+      let update = p / denom;
+      roots[i] = roots[i] - update;
+      let magnitude = update.norm();
+      if magnitude > max_update {
+        max_update = magnitude;
+      }
+    }
+    if max_update < tolerance {
+      break;
+    }
+  }
+
+  roots
+}
+
+#[cfg(feature = "num-complex")]
+fn eval_horner<F: Float>(coeffs: &[Complex<F>], x: Complex<F>) -> Complex<F> {
+  coeffs.iter().fold(Complex::new(F::zero(), F::zero()), |acc, &c| acc * x + c)
+}
+
+/// Real-root wrapper mirroring the closed-form analytical API: the roots of
+/// [`find_roots_polynomial`] whose imaginary part is within `tolerance` of
+/// zero, returned as real numbers in ascending order.
+#[cfg(feature = "num-complex")]
+pub fn find_roots_polynomial_real<F: Float>(coeffs: &[F], tolerance: F) -> Vec<F> {
+  let mut real_roots: Vec<F> = find_roots_polynomial(coeffs)
+    .into_iter()
+    .filter(|z| z.im.abs() <= tolerance)
+    .map(|z| z.re)
+    .collect();
+  real_roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+  real_roots
+}
+
+#[cfg(feature = "num-complex")]
+#[test]
+fn test_find_roots_polynomial() {
+  // x^5 - 1 = 0 has one real root (1) and two complex conjugate pairs.
+  let real_roots = find_roots_polynomial_real(&[1f64, 0f64, 0f64, 0f64, 0f64, -1f64], 1e-9);
+  assert_eq!(real_roots, [1f64]);
+
+  let all_roots = find_roots_polynomial(&[1f64, 0f64, 0f64, 0f64, 0f64, -1f64]);
+  assert_eq!(all_roots.len(), 5);
+}